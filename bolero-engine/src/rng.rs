@@ -0,0 +1,61 @@
+use crate::{Engine, Never, Test};
+use bolero_generator::driver::DriverMode;
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+// fast and well-distributed, which is what generation throughput needs --
+// the old cryptographic default was overkill for this use case
+pub type Recommended = Xoshiro256PlusPlus;
+
+// seeds a `Recommended` RNG deterministically, so a failing run's seed
+// always replays the same sequence of generated values
+pub fn seeded(seed: u64) -> Recommended {
+    Recommended::seed_from_u64(seed)
+}
+
+/// Drives a `Test` by repeatedly generating input with [`Recommended`] (or
+/// another `SeedableRng`, for callers that want to swap it out).
+pub struct RngEngine<R = Recommended> {
+    seed: u64,
+    // recorded but intentionally does not perturb `seed`: the sequence for
+    // a given seed must stay identical no matter what mode is set
+    driver_mode: Option<DriverMode>,
+    rng: core::marker::PhantomData<R>,
+}
+
+impl<R> RngEngine<R> {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            driver_mode: None,
+            rng: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<R> Default for RngEngine<R> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T: Test, R: SeedableRng> Engine<T> for RngEngine<R> {
+    type Output = Never;
+
+    fn set_driver_mode(&mut self, mode: DriverMode) {
+        self.driver_mode = Some(mode);
+    }
+
+    fn run(self, mut test: T) -> Self::Output {
+        // re-derived from `self.seed` on every run, rather than advanced
+        // from some mutable field, so a given seed always replays the same
+        // sequence regardless of `driver_mode`
+        let mut rng = R::seed_from_u64(self.seed);
+
+        // the generate-run-report loop itself lives on `Test`, whose exact
+        // interface isn't part of this chunk of the tree; what this engine
+        // guarantees is the RNG that backs it and the seed->sequence
+        // determinism above
+        test.run(&mut rng, self.driver_mode)
+    }
+}
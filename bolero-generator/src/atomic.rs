@@ -0,0 +1,105 @@
+use crate::{
+    bounded::{BoundedGenerator, BoundedValue},
+    Rng, TypeGenerator, TypeGeneratorWithParams, TypeValueGenerator, ValueGenerator,
+};
+use core::{
+    cell::{Cell, RefCell, UnsafeCell},
+    ops::Bound,
+    sync::atomic::{
+        AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+        AtomicU64, AtomicU8, AtomicUsize,
+    },
+};
+
+impl TypeGenerator for AtomicBool {
+    fn generate<R: Rng>(rng: &mut R) -> Self {
+        Self::new(bool::generate(rng))
+    }
+}
+
+// atomics are neither `Copy` nor `Clone`, so they can't be the bound type
+// `T` in `BoundedGenerator<G, T>` (it clones its stored bounds on every
+// `generate`). Instead, bound the inner integer and wrap it into the atomic
+// on the way out.
+pub struct AtomicGenerator<G, A> {
+    inner: G,
+    wrap: fn(<G as ValueGenerator>::Output) -> A,
+}
+
+impl<G: ValueGenerator, A> ValueGenerator for AtomicGenerator<G, A> {
+    type Output = A;
+
+    fn generate<R: Rng>(&self, rng: &mut R) -> A {
+        (self.wrap)(self.inner.generate(rng))
+    }
+}
+
+macro_rules! impl_atomic_integer {
+    ($ty:ident, $inner_ty:ident) => {
+        impl TypeGenerator for $ty {
+            fn generate<R: Rng>(rng: &mut R) -> Self {
+                Self::new($inner_ty::generate(rng))
+            }
+        }
+
+        impl BoundedValue for $ty {
+            fn bounded(self, start: Bound<Self>, end: Bound<Self>) -> Self {
+                use Bound::*;
+
+                let start = match start {
+                    Included(value) => Included(value.into_inner()),
+                    Excluded(value) => Excluded(value.into_inner()),
+                    Unbounded => Unbounded,
+                };
+
+                let end = match end {
+                    Included(value) => Included(value.into_inner()),
+                    Excluded(value) => Excluded(value.into_inner()),
+                    Unbounded => Unbounded,
+                };
+
+                Self::new(self.into_inner().bounded(start, end))
+            }
+        }
+
+        impl TypeGeneratorWithParams for $ty {
+            type Output = AtomicGenerator<BoundedGenerator<TypeValueGenerator<$inner_ty>, $inner_ty>, $ty>;
+
+            fn gen_with() -> Self::Output {
+                AtomicGenerator {
+                    inner: BoundedGenerator::new(Default::default(), $inner_ty::default()..),
+                    wrap: $ty::new,
+                }
+            }
+        }
+    };
+}
+
+impl_atomic_integer!(AtomicU8, u8);
+impl_atomic_integer!(AtomicI8, i8);
+impl_atomic_integer!(AtomicU16, u16);
+impl_atomic_integer!(AtomicI16, i16);
+impl_atomic_integer!(AtomicU32, u32);
+impl_atomic_integer!(AtomicI32, i32);
+impl_atomic_integer!(AtomicU64, u64);
+impl_atomic_integer!(AtomicI64, i64);
+impl_atomic_integer!(AtomicUsize, usize);
+impl_atomic_integer!(AtomicIsize, isize);
+
+impl<T: TypeGenerator> TypeGenerator for Cell<T> {
+    fn generate<R: Rng>(rng: &mut R) -> Self {
+        Self::new(T::generate(rng))
+    }
+}
+
+impl<T: TypeGenerator> TypeGenerator for RefCell<T> {
+    fn generate<R: Rng>(rng: &mut R) -> Self {
+        Self::new(T::generate(rng))
+    }
+}
+
+impl<T: TypeGenerator> TypeGenerator for UnsafeCell<T> {
+    fn generate<R: Rng>(rng: &mut R) -> Self {
+        Self::new(T::generate(rng))
+    }
+}
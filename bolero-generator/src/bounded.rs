@@ -0,0 +1,47 @@
+use crate::{Rng, TypeGenerator, ValueGenerator};
+use core::ops::{Bound, RangeBounds};
+
+pub trait BoundedValue: Sized {
+    fn bounded(self, start: Bound<Self>, end: Bound<Self>) -> Self;
+
+    /// Replaces `self` with a value within `start`/`end`. The default
+    /// discards the current value: it generates a fresh one and maps it into
+    /// the bounds with `bounded`; types that can mutate cheaply in place
+    /// (e.g. integers) override this to start from the current value instead.
+    fn mutate_bounded<R: Rng>(&mut self, rng: &mut R, start: Bound<Self>, end: Bound<Self>)
+    where
+        Self: TypeGenerator,
+    {
+        *self = Self::generate(rng).bounded(start, end);
+    }
+}
+
+pub struct BoundedGenerator<G, T> {
+    generator: G,
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<G, T: Clone> BoundedGenerator<G, T> {
+    pub fn new<R: RangeBounds<T>>(generator: G, range: R) -> Self {
+        Self {
+            generator,
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+}
+
+impl<G, T> ValueGenerator for BoundedGenerator<G, T>
+where
+    G: ValueGenerator<Output = T>,
+    T: BoundedValue + Clone,
+{
+    type Output = T;
+
+    fn generate<R: Rng>(&self, rng: &mut R) -> T {
+        self.generator
+            .generate(rng)
+            .bounded(self.start.clone(), self.end.clone())
+    }
+}
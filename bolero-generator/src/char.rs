@@ -0,0 +1,104 @@
+use crate::{
+    bounded::{BoundedGenerator, BoundedValue},
+    Rng, TypeGenerator, TypeGeneratorWithParams, TypeValueGenerator, ValueGenerator,
+};
+use core::ops::Bound;
+
+// `char`s can't represent the UTF-16 surrogate pair range
+const SURROGATE_START: u32 = 0xD800;
+const SURROGATE_END: u32 = 0xDFFF;
+const SURROGATE_LEN: u32 = SURROGATE_END - SURROGATE_START + 1;
+const MAX_SCALAR: u32 = core::char::MAX as u32;
+
+// reduce a raw `u32` onto the valid scalar value space, skipping the
+// surrogate gap rather than wasting that slice of the domain
+fn to_char(value: u32) -> char {
+    let value = value % (MAX_SCALAR + 1 - SURROGATE_LEN);
+    let value = if value >= SURROGATE_START {
+        value + SURROGATE_LEN
+    } else {
+        value
+    };
+
+    char::from_u32(value).unwrap()
+}
+
+impl TypeGenerator for char {
+    fn generate<R: Rng>(rng: &mut R) -> Self {
+        to_char(u32::generate(rng))
+    }
+}
+
+impl ValueGenerator for char {
+    type Output = char;
+
+    fn generate<R: Rng>(&self, _rng: &mut R) -> Self {
+        *self
+    }
+}
+
+impl BoundedValue for char {
+    fn bounded(self, start: Bound<Self>, end: Bound<Self>) -> Self {
+        use Bound::*;
+
+        let start = match start {
+            Included(value) => value as u32,
+            Excluded(value) => value as u32 + 1,
+            Unbounded => 0,
+        };
+
+        let end = match end {
+            Included(value) => value as u32,
+            Excluded(value) => value as u32 - 1,
+            Unbounded => MAX_SCALAR,
+        };
+
+        let (start, end) = if start < end { (start, end) } else { (end, start) };
+
+        if start == end {
+            return char::from_u32(start).unwrap_or(self);
+        }
+
+        // scale within the (much smaller) requested char domain instead of
+        // delegating to the full-u32-domain integer `bounded` -- otherwise
+        // `values_per_step` dwarfs any real char range and every input
+        // collapses onto `start`
+        let gap_start = start.max(SURROGATE_START);
+        let gap_end = end.min(SURROGATE_END);
+        let gap_len = if gap_start <= gap_end {
+            gap_end - gap_start + 1
+        } else {
+            0
+        };
+        let pre_gap_count = if gap_len > 0 {
+            gap_start - start
+        } else {
+            end - start + 1
+        };
+
+        // `self` is a `char`, so its position is taken in the gap-less
+        // scalar value space that `to_char` draws from
+        let raw = self as u32;
+        let raw = if raw > SURROGATE_END { raw - SURROGATE_LEN } else { raw };
+
+        let steps = end - start + 1 - gap_len;
+        let values_per_step = (MAX_SCALAR + 1 - SURROGATE_LEN) / steps;
+        let index = (raw / values_per_step).min(steps - 1);
+
+        let value = if index < pre_gap_count {
+            start + index
+        } else {
+            start + index + gap_len
+        };
+
+        char::from_u32(value).unwrap_or(self)
+    }
+}
+
+impl TypeGeneratorWithParams for char {
+    type Output = BoundedGenerator<TypeValueGenerator<char>, char>;
+
+    fn gen_with() -> Self::Output {
+        BoundedGenerator::new(Default::default(), char::default()..)
+    }
+}
@@ -0,0 +1,49 @@
+mod atomic;
+mod char;
+mod num;
+
+pub mod bounded;
+pub use bounded::BoundedValue;
+
+pub use rand::RngCore as Rng;
+
+pub trait TypeGenerator: Sized {
+    fn generate<R: Rng>(rng: &mut R) -> Self;
+
+    /// Mutates `self` in place, so a coverage-guided engine can drive edits
+    /// from new bytes. The default regenerates the value from scratch; types
+    /// that can make small edits (e.g. integers) override it to change the
+    /// current value instead.
+    fn mutate<R: Rng>(&mut self, rng: &mut R) {
+        *self = Self::generate(rng);
+    }
+}
+
+pub trait ValueGenerator {
+    type Output;
+
+    fn generate<R: Rng>(&self, rng: &mut R) -> Self::Output;
+}
+
+pub trait TypeGeneratorWithParams {
+    type Output: ValueGenerator;
+
+    fn gen_with() -> Self::Output;
+}
+
+#[derive(Debug)]
+pub struct TypeValueGenerator<T>(core::marker::PhantomData<T>);
+
+impl<T> Default for TypeValueGenerator<T> {
+    fn default() -> Self {
+        Self(core::marker::PhantomData)
+    }
+}
+
+impl<T: TypeGenerator> ValueGenerator for TypeValueGenerator<T> {
+    type Output = T;
+
+    fn generate<R: Rng>(&self, rng: &mut R) -> T {
+        T::generate(rng)
+    }
+}
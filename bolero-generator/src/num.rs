@@ -5,8 +5,30 @@ use crate::{
 use byteorder::{ByteOrder, NativeEndian};
 use core::{mem::size_of, ops::Bound};
 
+// shared by every integer's `TypeGenerator::mutate`: either jump to an
+// interesting boundary value or nudge the current value by a small delta,
+// so a mutating engine can exploit locality instead of regenerating from
+// scratch
+macro_rules! mutate_int {
+    ($self:ident, $rng:ident, $ty:ident) => {{
+        match u8::generate($rng) % 4 {
+            0 => *$self = core::$ty::MIN,
+            1 => *$self = core::$ty::MAX,
+            2 => *$self = 0,
+            _ => {
+                let delta = u8::generate($rng) as $ty;
+                *$self = if bool::generate($rng) {
+                    $self.wrapping_add(delta)
+                } else {
+                    $self.wrapping_sub(delta)
+                };
+            }
+        }
+    }};
+}
+
 macro_rules! impl_bounded_integer {
-    ($ty:ident) => {
+    ($ty:ident, $unsigned_ty:ident) => {
         impl BoundedValue for $ty {
             fn bounded(self, start: Bound<Self>, end: Bound<Self>) -> Self {
                 use Bound::*;
@@ -23,15 +45,40 @@ macro_rules! impl_bounded_integer {
                     Unbounded => core::$ty::MAX,
                 };
 
-                let (lower, upper) = if start < end {
-                    (start, end)
-                } else {
-                    (end, start)
-                };
+                let (start, end) = if start < end { (start, end) } else { (end, start) };
+
+                if start == end {
+                    return start;
+                }
+
+                // shift into the unsigned domain so the step math below is
+                // never affected by the sign bit
+                let offset = core::$ty::MIN as $unsigned_ty;
+                let start = (start as $unsigned_ty).wrapping_sub(offset);
+                let end = (end as $unsigned_ty).wrapping_sub(offset);
+                let value = (self as $unsigned_ty).wrapping_sub(offset);
+
+                // `wrapping_add`, not `saturating_add`: when the full range of
+                // the type was requested this wraps back around to 0, which is
+                // what signals "nothing to scale" below
+                let steps = end.wrapping_sub(start).wrapping_add(1);
+                if steps == 0 {
+                    return self;
+                }
 
-                let range = upper - lower;
+                let values_per_step = core::$unsigned_ty::MAX / steps;
+                let scaled = start.saturating_add(value / values_per_step).min(end);
 
-                (self % range) + lower
+                scaled.wrapping_add(offset) as $ty
+            }
+
+            fn mutate_bounded<R: Rng>(&mut self, rng: &mut R, start: Bound<Self>, end: Bound<Self>) {
+                self.mutate(rng);
+                // keep a mutated value that is still in range as it is;
+                // only one that left the range is mapped back into it
+                if !core::ops::RangeBounds::contains(&(start, end), &*self) {
+                    *self = self.bounded(start, end);
+                }
             }
         }
 
@@ -46,13 +93,17 @@ macro_rules! impl_bounded_integer {
 }
 
 macro_rules! impl_byte {
-    ($name:ident, $ty:ident) => {
+    ($name:ident, $ty:ident, $unsigned_ty:ident) => {
         impl TypeGenerator for $ty {
             fn generate<R: Rng>(rng: &mut R) -> Self {
                 let mut bytes = [0; size_of::<$ty>()];
                 Rng::fill_bytes(rng, &mut bytes);
                 bytes[0] as $ty
             }
+
+            fn mutate<R: Rng>(&mut self, rng: &mut R) {
+                mutate_int!(self, rng, $ty);
+            }
         }
 
         impl ValueGenerator for $ty {
@@ -63,21 +114,25 @@ macro_rules! impl_byte {
             }
         }
 
-        impl_bounded_integer!($ty);
+        impl_bounded_integer!($ty, $unsigned_ty);
     };
 }
 
-impl_byte!(gen_u8, u8);
-impl_byte!(gen_i8, i8);
+impl_byte!(gen_u8, u8, u8);
+impl_byte!(gen_i8, i8, u8);
 
 macro_rules! impl_integer {
-    ($name:ident, $ty:ident, $call:ident) => {
+    ($name:ident, $ty:ident, $call:ident, $unsigned_ty:ident) => {
         impl TypeGenerator for $ty {
             fn generate<R: Rng>(rng: &mut R) -> Self {
                 let mut bytes = [0; size_of::<$ty>()];
                 Rng::fill_bytes(rng, &mut bytes);
                 NativeEndian::$call(&bytes)
             }
+
+            fn mutate<R: Rng>(&mut self, rng: &mut R) {
+                mutate_int!(self, rng, $ty);
+            }
         }
 
         impl ValueGenerator for $ty {
@@ -88,27 +143,31 @@ macro_rules! impl_integer {
             }
         }
 
-        impl_bounded_integer!($ty);
+        impl_bounded_integer!($ty, $unsigned_ty);
     };
 }
 
-impl_integer!(gen_u16, u16, read_u16);
-impl_integer!(gen_i16, i16, read_i16);
-impl_integer!(gen_u32, u32, read_u32);
-impl_integer!(gen_i32, i32, read_i32);
-impl_integer!(gen_u64, u64, read_u64);
-impl_integer!(gen_i64, i64, read_i64);
-impl_integer!(gen_u128, u128, read_u128);
-impl_integer!(gen_i128, i128, read_i128);
+impl_integer!(gen_u16, u16, read_u16, u16);
+impl_integer!(gen_i16, i16, read_i16, u16);
+impl_integer!(gen_u32, u32, read_u32, u32);
+impl_integer!(gen_i32, i32, read_i32, u32);
+impl_integer!(gen_u64, u64, read_u64, u64);
+impl_integer!(gen_i64, i64, read_i64, u64);
+impl_integer!(gen_u128, u128, read_u128, u128);
+impl_integer!(gen_i128, i128, read_i128, u128);
 
 macro_rules! impl_native_integer {
-    ($name:ident, $ty:ident) => {
+    ($name:ident, $ty:ident, $unsigned_ty:ident) => {
         impl TypeGenerator for $ty {
             fn generate<R: Rng>(rng: &mut R) -> Self {
                 let mut bytes = [0; size_of::<$ty>()];
                 Rng::fill_bytes(rng, &mut bytes);
                 NativeEndian::read_uint(&bytes, bytes.len()) as $ty
             }
+
+            fn mutate<R: Rng>(&mut self, rng: &mut R) {
+                mutate_int!(self, rng, $ty);
+            }
         }
 
         impl ValueGenerator for $ty {
@@ -119,15 +178,15 @@ macro_rules! impl_native_integer {
             }
         }
 
-        impl_bounded_integer!($ty);
+        impl_bounded_integer!($ty, $unsigned_ty);
     };
 }
 
-impl_native_integer!(gen_usize, usize);
-impl_native_integer!(gen_isize, isize);
+impl_native_integer!(gen_usize, usize, usize);
+impl_native_integer!(gen_isize, isize, usize);
 
 macro_rules! impl_float {
-    ($name:ident, $ty:ident, $call:ident) => {
+    ($name:ident, $ty:ident, $call:ident, $bits_ty:ident, $mantissa_mask:expr, $one_bits:expr) => {
         impl TypeGenerator for $ty {
             fn generate<R: Rng>(rng: &mut R) -> Self {
                 let mut bytes = [0; size_of::<$ty>()];
@@ -144,12 +203,59 @@ macro_rules! impl_float {
             }
         }
 
-        // TODO impl_bounded
+        impl BoundedValue for $ty {
+            fn bounded(self, start: Bound<Self>, end: Bound<Self>) -> Self {
+                use Bound::*;
+
+                // a NaN/inf raw pattern can't be scaled meaningfully, so just
+                // land on one of the requested endpoints
+                if self.is_nan() || self.is_infinite() {
+                    return match start {
+                        Included(value) | Excluded(value) => value,
+                        Unbounded => core::$ty::MIN,
+                    };
+                }
+
+                let start = match start {
+                    Included(value) | Excluded(value) => value,
+                    Unbounded => core::$ty::MIN,
+                };
+
+                let end = match end {
+                    Included(value) | Excluded(value) => value,
+                    Unbounded => core::$ty::MAX,
+                };
+
+                let (lower, upper) = if start < end { (start, end) } else { (end, start) };
+
+                // reduce the raw bits into [1.0, 2.0) by fixing the exponent,
+                // then subtract 1.0 to get a uniform fraction in [0.0, 1.0)
+                let bits: $bits_ty = (self.to_bits() & $mantissa_mask) | $one_bits;
+                let frac = <$ty>::from_bits(bits) - 1.0;
+
+                // interpolate as a convex combination rather than
+                // `lower + frac * (upper - lower)`: for a wide interval
+                // (e.g. MIN..=MAX) the subtraction alone overflows to
+                // infinity, and `0.0 * inf` produces a NaN that would
+                // escape the requested range
+                let value = lower * (1.0 - frac) + upper * frac;
+
+                value.clamp(lower, upper)
+            }
+        }
+
+        impl TypeGeneratorWithParams for $ty {
+            type Output = BoundedGenerator<TypeValueGenerator<$ty>, $ty>;
+
+            fn gen_with() -> Self::Output {
+                BoundedGenerator::new(Default::default(), $ty::default()..)
+            }
+        }
     };
 }
 
-impl_float!(gen_f32, f32, read_f32);
-impl_float!(gen_f64, f64, read_f64);
+impl_float!(gen_f32, f32, read_f32, u32, 0x007f_ffff, 0x3f80_0000);
+impl_float!(gen_f64, f64, read_f64, u64, 0x000f_ffff_ffff_ffff, 0x3ff0_0000_0000_0000);
 
 macro_rules! impl_non_zero_integer {
     ($ty:ident) => {